@@ -0,0 +1,144 @@
+use std::io::{self, Write};
+
+use super::{Exporter, Mesh};
+
+/// Binary glTF (.glb): a JSON chunk describing a single indexed mesh
+/// primitive, followed by a binary chunk holding the raw vertex/index
+/// buffers it references.
+pub struct GlbExporter;
+
+impl Exporter for GlbExporter {
+    fn id(&self) -> &'static str {
+        "glb"
+    }
+
+    fn label(&self) -> &'static str {
+        "GLB"
+    }
+
+    fn extension(&self) -> &'static str {
+        "glb"
+    }
+
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()> {
+        let mut bin = Vec::new();
+
+        let positions_offset = bin.len();
+        for position in &mesh.positions {
+            for component in position {
+                bin.extend_from_slice(&component.to_le_bytes());
+            }
+        }
+        let positions_length = bin.len() - positions_offset;
+
+        let indices_offset = bin.len();
+        for index in &mesh.indices {
+            bin.extend_from_slice(&index.to_le_bytes());
+        }
+        let indices_length = bin.len() - indices_offset;
+
+        while bin.len() % 4 != 0 {
+            bin.push(0);
+        }
+
+        let (min, max) = bounds(&mesh.positions);
+        let json = [
+            r#"{"asset":{"version":"2.0","generator":"3d-gen-desktop"},"#.to_string(),
+            r#""scene":0,"scenes":[{"nodes":[0]}],"nodes":[{"mesh":0}],"#.to_string(),
+            r#""meshes":[{"primitives":[{"attributes":{"POSITION":0},"indices":1}]}],"#.to_string(),
+            format!(r#""buffers":[{{"byteLength":{}}}],"#, bin.len()),
+            format!(
+                r#""bufferViews":[{{"buffer":0,"byteOffset":{positions_offset},"byteLength":{positions_length},"target":34962}},"#
+            ),
+            format!(
+                r#"{{"buffer":0,"byteOffset":{indices_offset},"byteLength":{indices_length},"target":34963}}],"#
+            ),
+            format!(
+                r#""accessors":[{{"bufferView":0,"componentType":5126,"count":{},"type":"VEC3","min":[{},{},{}],"max":[{},{},{}]}},"#,
+                mesh.positions.len(),
+                min[0],
+                min[1],
+                min[2],
+                max[0],
+                max[1],
+                max[2],
+            ),
+            format!(
+                r#"{{"bufferView":1,"componentType":5125,"count":{},"type":"SCALAR"}}]}}"#,
+                mesh.indices.len()
+            ),
+        ]
+        .concat();
+
+        let mut json_bytes = json.into_bytes();
+        while json_bytes.len() % 4 != 0 {
+            json_bytes.push(b' ');
+        }
+
+        let total_length = 12 + 8 + json_bytes.len() + 8 + bin.len();
+
+        writer.write_all(b"glTF")?;
+        writer.write_all(&2u32.to_le_bytes())?;
+        writer.write_all(&(total_length as u32).to_le_bytes())?;
+
+        writer.write_all(&(json_bytes.len() as u32).to_le_bytes())?;
+        writer.write_all(b"JSON")?;
+        writer.write_all(&json_bytes)?;
+
+        writer.write_all(&(bin.len() as u32).to_le_bytes())?;
+        writer.write_all(b"BIN\0")?;
+        writer.write_all(&bin)?;
+
+        Ok(())
+    }
+}
+
+fn bounds(positions: &[[f32; 3]]) -> ([f32; 3], [f32; 3]) {
+    if positions.is_empty() {
+        return ([0.0; 3], [0.0; 3]);
+    }
+
+    let mut min = [f32::INFINITY; 3];
+    let mut max = [f32::NEG_INFINITY; 3];
+    for position in positions {
+        for axis in 0..3 {
+            min[axis] = min[axis].min(position[axis]);
+            max[axis] = max[axis].max(position[axis]);
+        }
+    }
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh {
+        Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            ..Mesh::default()
+        }
+    }
+
+    #[test]
+    fn header_and_chunk_lengths_are_consistent() {
+        let mut out = Vec::new();
+        GlbExporter.write(&triangle(), &mut out).unwrap();
+
+        assert_eq!(&out[0..4], b"glTF");
+        assert_eq!(u32::from_le_bytes(out[4..8].try_into().unwrap()), 2);
+        let total_length = u32::from_le_bytes(out[8..12].try_into().unwrap()) as usize;
+        assert_eq!(total_length, out.len());
+
+        let json_length = u32::from_le_bytes(out[12..16].try_into().unwrap()) as usize;
+        assert_eq!(&out[16..20], b"JSON");
+        let json = std::str::from_utf8(&out[20..20 + json_length]).unwrap();
+        assert!(json.trim_end().ends_with('}'));
+
+        let bin_offset = 20 + json_length;
+        let bin_length = u32::from_le_bytes(out[bin_offset..bin_offset + 4].try_into().unwrap());
+        assert_eq!(&out[bin_offset + 4..bin_offset + 8], b"BIN\0");
+        assert_eq!(bin_offset + 8 + bin_length as usize, out.len());
+    }
+}
@@ -0,0 +1,51 @@
+use std::io::{self, Write};
+
+mod glb;
+mod obj;
+mod ply;
+mod stl;
+mod usdz;
+
+/// A minimal triangle mesh: flat position/normal/uv buffers and a flat
+/// triangle index buffer, the common interchange shape passed to every
+/// exporter.
+#[derive(Default, Clone)]
+pub struct Mesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    pub uvs: Vec<[f32; 2]>,
+    /// Triangle indices into `positions`/`normals`/`uvs`, three per face.
+    pub indices: Vec<u32>,
+}
+
+/// A mesh export backend: one registration covers both the menu entry and
+/// the actual file write, so adding a format is one registration rather
+/// than new menu plumbing.
+pub trait Exporter: Send + Sync {
+    /// Stable identifier used in menu item ids and the `menu-action`
+    /// payload (e.g. `"glb"`, `"ply_binary"`).
+    fn id(&self) -> &'static str;
+    /// Human-readable label shown in the Export submenu.
+    fn label(&self) -> &'static str;
+    /// File extension (without the dot) suggested to the save dialog.
+    fn extension(&self) -> &'static str;
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()>;
+}
+
+/// Returns every registered exporter, in menu display order.
+pub fn registry() -> Vec<Box<dyn Exporter>> {
+    vec![
+        Box::new(glb::GlbExporter),
+        Box::new(stl::StlExporter),
+        Box::new(obj::ObjExporter),
+        Box::new(ply::PlyExporter { binary: false }),
+        Box::new(ply::PlyExporter { binary: true }),
+        Box::new(usdz::UsdzExporter),
+    ]
+}
+
+/// Looks up a registered exporter by id, used to dispatch a frontend export
+/// request (after the save path is chosen) to the matching backend.
+pub fn find(id: &str) -> Option<Box<dyn Exporter>> {
+    registry().into_iter().find(|exporter| exporter.id() == id)
+}
@@ -0,0 +1,102 @@
+use std::io::{self, Write};
+
+use super::{Exporter, Mesh};
+
+/// Wavefront OBJ: plain-text vertex/normal/uv lists followed by triangle
+/// face lines, the most broadly compatible interchange format.
+pub struct ObjExporter;
+
+impl Exporter for ObjExporter {
+    fn id(&self) -> &'static str {
+        "obj"
+    }
+
+    fn label(&self) -> &'static str {
+        "OBJ"
+    }
+
+    fn extension(&self) -> &'static str {
+        "obj"
+    }
+
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()> {
+        for p in &mesh.positions {
+            writeln!(writer, "v {} {} {}", p[0], p[1], p[2])?;
+        }
+        for n in &mesh.normals {
+            writeln!(writer, "vn {} {} {}", n[0], n[1], n[2])?;
+        }
+        for uv in &mesh.uvs {
+            writeln!(writer, "vt {} {}", uv[0], uv[1])?;
+        }
+
+        let has_normals = mesh.normals.len() == mesh.positions.len();
+        let has_uvs = mesh.uvs.len() == mesh.positions.len();
+
+        for face in mesh.indices.chunks_exact(3) {
+            writeln!(
+                writer,
+                "f {} {} {}",
+                obj_vertex(face[0], has_uvs, has_normals),
+                obj_vertex(face[1], has_uvs, has_normals),
+                obj_vertex(face[2], has_uvs, has_normals),
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats one OBJ face-vertex reference; OBJ indices are 1-based.
+fn obj_vertex(index: u32, has_uvs: bool, has_normals: bool) -> String {
+    let index = index + 1;
+    match (has_uvs, has_normals) {
+        (true, true) => format!("{index}/{index}/{index}"),
+        (true, false) => format!("{index}/{index}"),
+        (false, true) => format!("{index}//{index}"),
+        (false, false) => format!("{index}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh {
+        Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            ..Mesh::default()
+        }
+    }
+
+    fn write(mesh: &Mesh) -> String {
+        let mut out = Vec::new();
+        ObjExporter.write(mesh, &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn writes_one_vertex_line_per_position_and_one_face_line_per_triangle() {
+        let text = write(&triangle());
+        assert_eq!(
+            text.lines().filter(|line| line.starts_with("v ")).count(),
+            3
+        );
+        assert_eq!(
+            text.lines().filter(|line| line.starts_with("f ")).count(),
+            1
+        );
+        assert!(text.lines().any(|line| line == "f 1 2 3"));
+    }
+
+    #[test]
+    fn mismatched_normals_fall_back_to_position_only_indices() {
+        let mesh = Mesh {
+            normals: vec![[0.0, 0.0, 1.0]],
+            ..triangle()
+        };
+        let text = write(&mesh);
+        assert!(text.lines().any(|line| line == "f 1 2 3"));
+    }
+}
@@ -0,0 +1,116 @@
+use std::io::{self, Write};
+
+use super::{Exporter, Mesh};
+
+/// Stanford PLY, in either its ASCII or binary_little_endian variant; both
+/// share the same header apart from its `format` line and otherwise differ
+/// only in how the vertex/face data is written.
+pub struct PlyExporter {
+    pub binary: bool,
+}
+
+impl Exporter for PlyExporter {
+    fn id(&self) -> &'static str {
+        if self.binary {
+            "ply_binary"
+        } else {
+            "ply_ascii"
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        if self.binary {
+            "PLY (Binary)"
+        } else {
+            "PLY (ASCII)"
+        }
+    }
+
+    fn extension(&self) -> &'static str {
+        "ply"
+    }
+
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()> {
+        let format = if self.binary {
+            "binary_little_endian 1.0"
+        } else {
+            "ascii 1.0"
+        };
+
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format {format}")?;
+        writeln!(writer, "element vertex {}", mesh.positions.len())?;
+        writeln!(writer, "property float x")?;
+        writeln!(writer, "property float y")?;
+        writeln!(writer, "property float z")?;
+        writeln!(writer, "element face {}", mesh.indices.len() / 3)?;
+        writeln!(writer, "property list uchar int vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        if self.binary {
+            for p in &mesh.positions {
+                for component in p {
+                    writer.write_all(&component.to_le_bytes())?;
+                }
+            }
+            for face in mesh.indices.chunks_exact(3) {
+                writer.write_all(&[3u8])?;
+                for index in face {
+                    writer.write_all(&index.to_le_bytes())?;
+                }
+            }
+        } else {
+            for p in &mesh.positions {
+                writeln!(writer, "{} {} {}", p[0], p[1], p[2])?;
+            }
+            for face in mesh.indices.chunks_exact(3) {
+                writeln!(writer, "3 {} {} {}", face[0], face[1], face[2])?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh {
+        Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            ..Mesh::default()
+        }
+    }
+
+    #[test]
+    fn ascii_header_counts_match_mesh_and_body_line_counts() {
+        let mut out = Vec::new();
+        PlyExporter { binary: false }
+            .write(&triangle(), &mut out)
+            .unwrap();
+        let text = String::from_utf8(out).unwrap();
+
+        assert!(text.contains("element vertex 3"));
+        assert!(text.contains("element face 1"));
+        let body = text.split("end_header\n").nth(1).unwrap();
+        assert_eq!(body.lines().count(), 4);
+    }
+
+    #[test]
+    fn binary_body_length_matches_vertex_and_face_counts() {
+        let mut out = Vec::new();
+        PlyExporter { binary: true }
+            .write(&triangle(), &mut out)
+            .unwrap();
+
+        let header_end = out
+            .windows(b"end_header\n".len())
+            .position(|window| window == b"end_header\n")
+            .unwrap()
+            + b"end_header\n".len();
+        let body = &out[header_end..];
+        assert_eq!(body.len(), 3 * 12 + 1 * (1 + 3 * 4));
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::{self, Write};
+
+use super::{Exporter, Mesh};
+
+/// Binary STL: an 80-byte header, a triangle count, then 50 bytes per
+/// triangle (facet normal, three vertices, and an unused attribute count).
+pub struct StlExporter;
+
+impl Exporter for StlExporter {
+    fn id(&self) -> &'static str {
+        "stl"
+    }
+
+    fn label(&self) -> &'static str {
+        "STL"
+    }
+
+    fn extension(&self) -> &'static str {
+        "stl"
+    }
+
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()> {
+        let mut header = [0u8; 80];
+        let banner = b"3d-gen-desktop export";
+        header[..banner.len()].copy_from_slice(banner);
+        writer.write_all(&header)?;
+
+        let triangle_count = (mesh.indices.len() / 3) as u32;
+        writer.write_all(&triangle_count.to_le_bytes())?;
+
+        for face in mesh.indices.chunks_exact(3) {
+            let a = mesh.positions[face[0] as usize];
+            let b = mesh.positions[face[1] as usize];
+            let c = mesh.positions[face[2] as usize];
+
+            write_vec3(writer, face_normal(a, b, c))?;
+            write_vec3(writer, a)?;
+            write_vec3(writer, b)?;
+            write_vec3(writer, c)?;
+            writer.write_all(&0u16.to_le_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+fn write_vec3(writer: &mut dyn Write, v: [f32; 3]) -> io::Result<()> {
+    for component in v {
+        writer.write_all(&component.to_le_bytes())?;
+    }
+    Ok(())
+}
+
+fn face_normal(a: [f32; 3], b: [f32; 3], c: [f32; 3]) -> [f32; 3] {
+    let u = sub(b, a);
+    let v = sub(c, a);
+    let normal = [
+        u[1] * v[2] - u[2] * v[1],
+        u[2] * v[0] - u[0] * v[2],
+        u[0] * v[1] - u[1] * v[0],
+    ];
+    let length = (normal[0].powi(2) + normal[1].powi(2) + normal[2].powi(2)).sqrt();
+    if length == 0.0 {
+        normal
+    } else {
+        [normal[0] / length, normal[1] / length, normal[2] / length]
+    }
+}
+
+fn sub(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh {
+        Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            ..Mesh::default()
+        }
+    }
+
+    #[test]
+    fn body_length_matches_triangle_count() {
+        let mut out = Vec::new();
+        StlExporter.write(&triangle(), &mut out).unwrap();
+
+        let triangle_count = u32::from_le_bytes(out[80..84].try_into().unwrap());
+        assert_eq!(triangle_count, 1);
+        assert_eq!(out.len(), 80 + 4 + triangle_count as usize * 50);
+    }
+
+    #[test]
+    fn empty_mesh_has_zero_triangles() {
+        let mut out = Vec::new();
+        StlExporter.write(&Mesh::default(), &mut out).unwrap();
+
+        assert_eq!(&out[84..], &[] as &[u8]);
+        assert_eq!(u32::from_le_bytes(out[80..84].try_into().unwrap()), 0);
+    }
+}
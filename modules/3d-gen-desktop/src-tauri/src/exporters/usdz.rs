@@ -0,0 +1,173 @@
+use std::io::{self, Write};
+
+use super::{Exporter, Mesh};
+
+const ASSET_NAME: &str = "model.usda";
+
+/// USDZ: a single USD ASCII (.usda) asset packaged into an uncompressed
+/// zip archive, which is all a USDZ container is.
+pub struct UsdzExporter;
+
+impl Exporter for UsdzExporter {
+    fn id(&self) -> &'static str {
+        "usdz"
+    }
+
+    fn label(&self) -> &'static str {
+        "USDZ"
+    }
+
+    fn extension(&self) -> &'static str {
+        "usdz"
+    }
+
+    fn write(&self, mesh: &Mesh, writer: &mut dyn Write) -> io::Result<()> {
+        let usda = to_usda(mesh);
+        write_usdz(writer, ASSET_NAME, usda.as_bytes())
+    }
+}
+
+fn to_usda(mesh: &Mesh) -> String {
+    let points = mesh
+        .positions
+        .iter()
+        .map(|p| format!("({}, {}, {})", p[0], p[1], p[2]))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let face_vertex_counts = vec!["3"; mesh.indices.len() / 3].join(", ");
+    let face_vertex_indices = mesh
+        .indices
+        .iter()
+        .map(u32::to_string)
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    format!(
+        "#usda 1.0\n\
+         def Xform \"Model\"\n\
+         {{\n\
+         \x20   def Mesh \"Mesh\"\n\
+         \x20   {{\n\
+         \x20       point3f[] points = [{points}]\n\
+         \x20       int[] faceVertexCounts = [{face_vertex_counts}]\n\
+         \x20       int[] faceVertexIndices = [{face_vertex_indices}]\n\
+         \x20   }}\n\
+         }}\n"
+    )
+}
+
+/// Packages a single asset into a USDZ container: a zip archive with no
+/// compression, with the file data aligned to a 64-byte boundary as
+/// required by Apple's USDZ spec.
+fn write_usdz(writer: &mut dyn Write, name: &str, data: &[u8]) -> io::Result<()> {
+    let crc = crc32(data);
+    let name_bytes = name.as_bytes();
+
+    let header_len = 30 + name_bytes.len();
+    let extra_len = (64 - (header_len % 64)) % 64;
+
+    let mut local_header = Vec::new();
+    local_header.extend_from_slice(&0x0403_4b50u32.to_le_bytes());
+    local_header.extend_from_slice(&20u16.to_le_bytes()); // version needed
+    local_header.extend_from_slice(&0u16.to_le_bytes()); // flags
+    local_header.extend_from_slice(&0u16.to_le_bytes()); // method: stored
+    local_header.extend_from_slice(&0u16.to_le_bytes()); // mod time
+    local_header.extend_from_slice(&0u16.to_le_bytes()); // mod date
+    local_header.extend_from_slice(&crc.to_le_bytes());
+    local_header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    local_header.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    local_header.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+    local_header.extend_from_slice(&(extra_len as u16).to_le_bytes());
+    local_header.extend_from_slice(name_bytes);
+    local_header.extend(std::iter::repeat(0u8).take(extra_len));
+
+    writer.write_all(&local_header)?;
+    writer.write_all(data)?;
+
+    let local_header_offset = 0u32;
+    let mut central_directory = Vec::new();
+    central_directory.extend_from_slice(&0x0201_4b50u32.to_le_bytes());
+    central_directory.extend_from_slice(&20u16.to_le_bytes()); // version made by
+    central_directory.extend_from_slice(&20u16.to_le_bytes()); // version needed
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // flags
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // method
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod time
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // mod date
+    central_directory.extend_from_slice(&crc.to_le_bytes());
+    central_directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    central_directory.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    central_directory.extend_from_slice(&(name_bytes.len() as u16).to_le_bytes());
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // extra len
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // comment len
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // disk number
+    central_directory.extend_from_slice(&0u16.to_le_bytes()); // internal attrs
+    central_directory.extend_from_slice(&0u32.to_le_bytes()); // external attrs
+    central_directory.extend_from_slice(&local_header_offset.to_le_bytes());
+    central_directory.extend_from_slice(name_bytes);
+
+    let central_directory_offset = (local_header.len() + data.len()) as u32;
+    writer.write_all(&central_directory)?;
+
+    let mut end_of_central_directory = Vec::new();
+    end_of_central_directory.extend_from_slice(&0x0605_4b50u32.to_le_bytes());
+    end_of_central_directory.extend_from_slice(&0u16.to_le_bytes()); // disk number
+    end_of_central_directory.extend_from_slice(&0u16.to_le_bytes()); // cd start disk
+    end_of_central_directory.extend_from_slice(&1u16.to_le_bytes()); // entries on disk
+    end_of_central_directory.extend_from_slice(&1u16.to_le_bytes()); // total entries
+    end_of_central_directory.extend_from_slice(&(central_directory.len() as u32).to_le_bytes());
+    end_of_central_directory.extend_from_slice(&central_directory_offset.to_le_bytes());
+    end_of_central_directory.extend_from_slice(&0u16.to_le_bytes()); // comment len
+
+    writer.write_all(&end_of_central_directory)
+}
+
+/// Standard zip CRC-32 (polynomial 0xEDB88320), hand-rolled since this is
+/// the only place the archive format is touched.
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn triangle() -> Mesh {
+        Mesh {
+            positions: vec![[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+            indices: vec![0, 1, 2],
+            ..Mesh::default()
+        }
+    }
+
+    #[test]
+    fn crc32_matches_known_vector() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn zip_signatures_and_central_directory_offset_are_correct() {
+        let mut out = Vec::new();
+        UsdzExporter.write(&triangle(), &mut out).unwrap();
+
+        assert_eq!(&out[0..4], &0x0403_4b50u32.to_le_bytes());
+
+        let eocd = out.len() - 22;
+        assert_eq!(&out[eocd..eocd + 4], &0x0605_4b50u32.to_le_bytes());
+
+        let cd_size = u32::from_le_bytes(out[eocd + 12..eocd + 16].try_into().unwrap()) as usize;
+        let cd_offset = u32::from_le_bytes(out[eocd + 16..eocd + 20].try_into().unwrap()) as usize;
+        assert_eq!(cd_offset + cd_size, eocd);
+        assert_eq!(
+            &out[cd_offset..cd_offset + 4],
+            &0x0201_4b50u32.to_le_bytes()
+        );
+    }
+}
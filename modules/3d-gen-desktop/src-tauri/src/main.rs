@@ -1,49 +1,408 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
-use tauri::menu::{Menu, MenuItem, Submenu};
-use tauri::{Emitter, Runtime};
+mod exporters;
+mod recent;
+mod updater;
 
-fn build_menu<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<Menu<R>> {
-    let export_menu = Submenu::with_items(
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::menu::{IsMenuItem, Menu, MenuItem, PredefinedMenuItem, Submenu};
+use tauri::tray::{MouseButton, MouseButtonState, TrayIconBuilder, TrayIconEvent};
+use tauri::{Emitter, Manager, Runtime};
+use tauri_plugin_dialog::{DialogExt, MessageDialogKind};
+
+/// Menu items whose enabled state changes after the menu is built, keyed by
+/// menu item id (e.g. `menu_export_glb`) so both the File menu and tray
+/// entries toggle together.
+struct MenuHandles<R: Runtime> {
+    export_items: Mutex<HashMap<String, Vec<MenuItem<R>>>>,
+    recent: Submenu<R>,
+}
+
+/// Registers `item` under `id` in the shared export-item map.
+fn register_export_item<R: Runtime>(app: &tauri::AppHandle<R>, id: &str, item: MenuItem<R>) {
+    let handles = app.state::<MenuHandles<R>>();
+    handles
+        .export_items
+        .lock()
+        .unwrap()
+        .entry(id.to_string())
+        .or_default()
+        .push(item);
+}
+
+const REIMPORT_ID_PREFIX: &str = "reimport::";
+const EXPORT_ID_PREFIX: &str = "menu_export_";
+
+/// Builds the "Recent" submenu items from the persisted import history, or a
+/// single disabled placeholder when there is no history yet.
+fn build_recent_items<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    entries: &[PathBuf],
+) -> tauri::Result<Vec<MenuItem<R>>> {
+    if entries.is_empty() {
+        return Ok(vec![MenuItem::with_id(
+            app,
+            "reimport_none",
+            "No Recent Imports",
+            false,
+            None::<&str>,
+        )?]);
+    }
+
+    entries
+        .iter()
+        .map(|path| {
+            let label = path
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_else(|| path.to_string_lossy().into_owned());
+            MenuItem::with_id(
+                app,
+                format!("{REIMPORT_ID_PREFIX}{}", path.to_string_lossy()),
+                label,
+                true,
+                None::<&str>,
+            )
+        })
+        .collect()
+}
+
+/// Builds one menu item per registered exporter, so adding a format to the
+/// `exporters` registry is all that's needed to add it to this submenu.
+fn build_export_items<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+) -> tauri::Result<Vec<(String, MenuItem<R>)>> {
+    exporters::registry()
+        .iter()
+        .map(|exporter| {
+            let id = format!("{EXPORT_ID_PREFIX}{}", exporter.id());
+            let accelerator = (exporter.id() == "glb").then_some("CmdOrCtrl+Shift+E");
+            let item = MenuItem::with_id(app, &id, exporter.label(), false, accelerator)?;
+            Ok((id, item))
+        })
+        .collect()
+}
+
+/// Builds the macOS application submenu (About/Settings/Hide/Quit); must be
+/// passed first to `Menu::with_items` since Tauri renders a menu's first
+/// submenu as the NSApp application menu.
+#[cfg(target_os = "macos")]
+fn build_app_menu<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    settings_item: &MenuItem<R>,
+) -> tauri::Result<Submenu<R>> {
+    Submenu::with_items(
         app,
-        "Export",
+        "3D Gen",
         true,
         &[
-            &MenuItem::with_id(app, "menu_export_glb", "GLB", true, None::<&str>)?,
-            &MenuItem::with_id(app, "menu_export_stl", "STL", true, None::<&str>)?,
+            &PredefinedMenuItem::about(app, None, None)?,
+            &PredefinedMenuItem::separator(app)?,
+            settings_item,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::hide(app, None)?,
+            &PredefinedMenuItem::hide_others(app, None)?,
+            &PredefinedMenuItem::separator(app)?,
+            &PredefinedMenuItem::quit(app, None)?,
         ],
-    )?;
+    )
+}
 
-    let file_menu = Submenu::with_items(
+fn build_menu<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<Menu<R>> {
+    let export_items = build_export_items(app)?;
+    let export_refs: Vec<&dyn IsMenuItem<R>> = export_items
+        .iter()
+        .map(|(_, item)| item as &dyn IsMenuItem<R>)
+        .collect();
+    let export_menu = Submenu::with_items(app, "Export", true, &export_refs)?;
+
+    let recent_items = build_recent_items(app, &recent::load(app))?;
+    let recent_refs: Vec<&dyn IsMenuItem<R>> = recent_items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<R>)
+        .collect();
+    let recent_menu = Submenu::with_items(app, "Recent", true, &recent_refs)?;
+
+    // On macOS, Settings lives in the application menu instead of File.
+    let settings_item =
+        MenuItem::with_id(app, "menu_settings", "Settings", true, Some("CmdOrCtrl+,"))?;
+
+    let mut file_items: Vec<&dyn IsMenuItem<R>> = Vec::new();
+    #[cfg(not(target_os = "macos"))]
+    file_items.push(&settings_item);
+    let logs_item = MenuItem::with_id(app, "menu_logs", "Logs", true, None::<&str>)?;
+    file_items.push(&logs_item);
+    let import_item = MenuItem::with_id(app, "menu_import", "Import", true, Some("CmdOrCtrl+I"))?;
+    file_items.push(&import_item);
+    file_items.push(&recent_menu);
+    file_items.push(&export_menu);
+    let check_updates_item = MenuItem::with_id(
         app,
-        "File",
+        "menu_check_updates",
+        "Check for Updates",
         true,
+        None::<&str>,
+    )?;
+    file_items.push(&check_updates_item);
+
+    let file_menu = Submenu::with_items(app, "File", true, &file_items)?;
+
+    app.manage(MenuHandles {
+        export_items: Mutex::new(
+            export_items
+                .into_iter()
+                .map(|(id, item)| (id, vec![item]))
+                .collect(),
+        ),
+        recent: recent_menu,
+    });
+
+    #[cfg(target_os = "macos")]
+    let menu = {
+        let app_menu = build_app_menu(app, &settings_item)?;
+        Menu::with_items(app, &[&app_menu, &file_menu])?
+    };
+    #[cfg(not(target_os = "macos"))]
+    let menu = Menu::with_items(app, &[&file_menu])?;
+
+    Ok(menu)
+}
+
+/// Rebuilds the "Recent" submenu contents in place, called by
+/// `refresh_recent_menu` after the import history changes.
+fn rebuild_recent_menu<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    entries: &[PathBuf],
+) -> tauri::Result<()> {
+    let handles = app.state::<MenuHandles<R>>();
+    for item in handles.recent.items()? {
+        handles.recent.remove(&item)?;
+    }
+    let items = build_recent_items(app, entries)?;
+    let refs: Vec<&dyn IsMenuItem<R>> = items
+        .iter()
+        .map(|item| item as &dyn IsMenuItem<R>)
+        .collect();
+    handles.recent.append_items(&refs)?;
+    Ok(())
+}
+
+/// Records `path` as the most recently imported file and refreshes the
+/// "Recent" menu so the change is visible immediately.
+#[tauri::command]
+fn refresh_recent_menu(app: tauri::AppHandle, path: String) -> tauri::Result<()> {
+    let entries = recent::push(&app, PathBuf::from(path))?;
+    rebuild_recent_menu(&app, &entries)
+}
+
+/// The wire shape of a mesh as sent from the frontend scene; converted into
+/// an `exporters::Mesh` before being handed to the chosen exporter.
+#[derive(serde::Deserialize)]
+struct MeshPayload {
+    positions: Vec<[f32; 3]>,
+    #[serde(default)]
+    normals: Vec<[f32; 3]>,
+    #[serde(default)]
+    uvs: Vec<[f32; 2]>,
+    indices: Vec<u32>,
+}
+
+impl From<MeshPayload> for exporters::Mesh {
+    fn from(payload: MeshPayload) -> Self {
+        exporters::Mesh {
+            positions: payload.positions,
+            normals: payload.normals,
+            uvs: payload.uvs,
+            indices: payload.indices,
+        }
+    }
+}
+
+fn io_error(message: impl Into<String>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidInput, message.into())
+}
+
+/// Checks that every triangle index is in bounds for `positions`.
+fn validate_indices(mesh: &MeshPayload) -> std::io::Result<()> {
+    let vertex_count = mesh.positions.len() as u32;
+    match mesh.indices.iter().find(|&&index| index >= vertex_count) {
+        Some(&index) => Err(io_error(format!(
+            "vertex index {index} is out of bounds for {vertex_count} position(s)"
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Writes the given mesh to `path` using the exporter registered under `id`.
+#[tauri::command]
+fn export_mesh(id: String, path: String, mesh: MeshPayload) -> tauri::Result<()> {
+    let exporter =
+        exporters::find(&id).ok_or_else(|| io_error(format!("unknown export format '{id}'")))?;
+    validate_indices(&mesh)?;
+    let mut file = std::fs::File::create(path)?;
+    exporter.write(&mesh.into(), &mut file)?;
+    Ok(())
+}
+
+/// Enables or disables every menu item registered under `id` (File menu and
+/// tray alike).
+#[tauri::command]
+fn set_menu_enabled(app: tauri::AppHandle, id: String, enabled: bool) -> tauri::Result<()> {
+    let handles = app.state::<MenuHandles<tauri::Wry>>();
+    let items = handles.export_items.lock().unwrap();
+    match items.get(&id) {
+        Some(items) => items.iter().try_for_each(|item| item.set_enabled(enabled)),
+        None => Ok(()),
+    }
+}
+
+/// Builds the tray menu, a compact mirror of the File menu so the app stays
+/// controllable while the main window is hidden during a long mesh job.
+fn build_tray<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<()> {
+    let export_glb = MenuItem::with_id(app, "menu_export_glb", "Export GLB", false, None::<&str>)?;
+    let export_stl = MenuItem::with_id(app, "menu_export_stl", "Export STL", false, None::<&str>)?;
+
+    let tray_menu = Menu::with_items(
+        app,
         &[
-            &MenuItem::with_id(app, "menu_settings", "Settings", true, None::<&str>)?,
-            &MenuItem::with_id(app, "menu_logs", "Logs", true, None::<&str>)?,
+            &MenuItem::with_id(app, "tray_show", "Show", true, None::<&str>)?,
+            &MenuItem::with_id(app, "tray_hide", "Hide", true, None::<&str>)?,
             &MenuItem::with_id(app, "menu_import", "Import", true, None::<&str>)?,
-            &export_menu,
+            &export_glb,
+            &export_stl,
+            &MenuItem::with_id(app, "tray_quit", "Quit", true, None::<&str>)?,
         ],
     )?;
 
-    Menu::with_items(app, &[&file_menu])
+    register_export_item(app, "menu_export_glb", export_glb);
+    register_export_item(app, "menu_export_stl", export_stl);
+
+    TrayIconBuilder::new()
+        .menu(&tray_menu)
+        .on_menu_event(|app, event| match event.id().as_ref() {
+            "tray_show" => show_window(app),
+            "tray_hide" => hide_window(app),
+            "tray_quit" => app.exit(0),
+            id => dispatch_menu_action(app, id),
+        })
+        .on_tray_icon_event(|tray, event| {
+            if let TrayIconEvent::Click {
+                button: MouseButton::Left,
+                button_state: MouseButtonState::Up,
+                ..
+            } = event
+            {
+                let app = tray.app_handle();
+                if let Some(window) = app.get_webview_window("main") {
+                    match window.is_visible() {
+                        Ok(true) => hide_window(app),
+                        _ => show_window(app),
+                    }
+                }
+            }
+        })
+        .build(app)?;
+
+    Ok(())
+}
+
+fn show_window<R: Runtime>(app: &tauri::AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.show();
+        let _ = window.set_focus();
+    }
+    let _ = app.emit("tray-action", "show");
+}
+
+fn hide_window<R: Runtime>(app: &tauri::AppHandle<R>) {
+    if let Some(window) = app.get_webview_window("main") {
+        let _ = window.hide();
+    }
+    let _ = app.emit("tray-action", "hide");
+}
+
+fn emit_menu_action<R: Runtime>(app: &tauri::AppHandle<R>, action: &str) -> tauri::Result<()> {
+    app.emit("menu-action", action)
+}
+
+fn handle_settings<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<()> {
+    emit_menu_action(app, "menu_settings")
+}
+
+fn handle_logs<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<()> {
+    emit_menu_action(app, "menu_logs")
 }
 
-fn emit_menu_action<R: Runtime>(app: &tauri::AppHandle<R>, action: &str) {
-    let _ = app.emit("menu-action", action);
+fn handle_import<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<()> {
+    emit_menu_action(app, "menu_import")
+}
+
+fn handle_reimport<R: Runtime>(app: &tauri::AppHandle<R>, path: &str) -> tauri::Result<()> {
+    app.emit("reimport", path)
+}
+
+/// Kicks off a user-triggered update check in the background.
+fn handle_check_updates<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<()> {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        if let Err(err) = updater::check_and_install(app.clone()).await {
+            log::error!("update check failed: {err}");
+            app.dialog()
+                .message(format!("Update check failed: {err}"))
+                .kind(MessageDialogKind::Error)
+                .title("3D Gen")
+                .blocking_show();
+        }
+    });
+    Ok(())
+}
+
+/// Runs the handler for a menu/tray action id, surfacing any failure via a
+/// native dialog and the app log.
+fn dispatch_menu_action<R: Runtime>(app: &tauri::AppHandle<R>, id: &str) {
+    let result = if let Some(path) = id.strip_prefix(REIMPORT_ID_PREFIX) {
+        handle_reimport(app, path)
+    } else if id.starts_with(EXPORT_ID_PREFIX) {
+        emit_menu_action(app, id)
+    } else {
+        match id {
+            "menu_settings" => handle_settings(app),
+            "menu_logs" => handle_logs(app),
+            "menu_import" => handle_import(app),
+            "menu_check_updates" => handle_check_updates(app),
+            _ => Ok(()),
+        }
+    };
+
+    if let Err(err) = result {
+        log::error!("menu action '{id}' failed: {err}");
+        app.dialog()
+            .message(format!("'{id}' failed: {err}"))
+            .kind(MessageDialogKind::Error)
+            .title("3D Gen")
+            .blocking_show();
+    }
 }
 
 fn main() {
     tauri::Builder::default()
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_updater::Builder::new().build())
         .menu(|app| build_menu(app))
-        .on_menu_event(|app, event| match event.id().as_ref() {
-            "menu_settings" => emit_menu_action(app, "menu_settings"),
-            "menu_logs" => emit_menu_action(app, "menu_logs"),
-            "menu_import" => emit_menu_action(app, "menu_import"),
-            "menu_export_glb" => emit_menu_action(app, "menu_export_glb"),
-            "menu_export_stl" => emit_menu_action(app, "menu_export_stl"),
-            _ => {}
+        .setup(|app| {
+            build_tray(app.handle())?;
+            updater::check_on_startup(app.handle());
+            Ok(())
         })
+        .on_menu_event(|app, event| dispatch_menu_action(app, event.id().as_ref()))
+        .invoke_handler(tauri::generate_handler![
+            set_menu_enabled,
+            refresh_recent_menu,
+            export_mesh
+        ])
         .run(tauri::generate_context!())
         .expect("error while running 3d-gen desktop app");
 }
@@ -0,0 +1,49 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tauri::{Manager, Runtime};
+
+const RECENT_IMPORTS_FILE: &str = "recent_imports.json";
+const MAX_RECENT_IMPORTS: usize = 10;
+
+/// Loads the list of recently imported file paths, most recent first, or an
+/// empty list if the file is missing or unreadable.
+pub fn load<R: Runtime>(app: &tauri::AppHandle<R>) -> Vec<PathBuf> {
+    let Ok(path) = recent_imports_path(app) else {
+        return Vec::new();
+    };
+    fs::read_to_string(path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Persists the list of recently imported file paths to the app config
+/// directory, creating the directory if needed.
+pub fn save<R: Runtime>(app: &tauri::AppHandle<R>, entries: &[PathBuf]) -> tauri::Result<()> {
+    let path = recent_imports_path(app)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let json = serde_json::to_string_pretty(entries)?;
+    fs::write(path, json)?;
+    Ok(())
+}
+
+/// Records a newly imported path as the most recent entry, de-duplicating
+/// against earlier entries and truncating to `MAX_RECENT_IMPORTS`.
+pub fn push<R: Runtime>(
+    app: &tauri::AppHandle<R>,
+    imported: PathBuf,
+) -> tauri::Result<Vec<PathBuf>> {
+    let mut entries = load(app);
+    entries.retain(|existing| existing != &imported);
+    entries.insert(0, imported);
+    entries.truncate(MAX_RECENT_IMPORTS);
+    save(app, &entries)?;
+    Ok(entries)
+}
+
+fn recent_imports_path<R: Runtime>(app: &tauri::AppHandle<R>) -> tauri::Result<PathBuf> {
+    Ok(app.path().app_config_dir()?.join(RECENT_IMPORTS_FILE))
+}
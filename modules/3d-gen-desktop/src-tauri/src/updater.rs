@@ -0,0 +1,117 @@
+use tauri::{Emitter, Runtime};
+use tauri_plugin_dialog::{DialogExt, MessageDialogButtons, MessageDialogKind};
+use tauri_plugin_updater::UpdaterExt;
+
+/// Status events emitted on `updater-event` as an update check progresses.
+#[derive(Clone, serde::Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+enum UpdaterEvent {
+    Checking,
+    UpToDate,
+    Available {
+        version: String,
+    },
+    Downloading {
+        chunk_length: usize,
+        content_length: Option<u64>,
+    },
+    Downloaded,
+    Error {
+        message: String,
+    },
+}
+
+fn emit<R: Runtime>(app: &tauri::AppHandle<R>, event: UpdaterEvent) {
+    let _ = app.emit("updater-event", event);
+}
+
+/// Runs a silent update check at startup; only emits an event when an
+/// update is actually available, and logs rather than surfaces failures.
+pub fn check_on_startup<R: Runtime>(app: &tauri::AppHandle<R>) {
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let updater = match app.updater() {
+            Ok(updater) => updater,
+            Err(err) => {
+                log::warn!("updater unavailable: {err}");
+                return;
+            }
+        };
+
+        match updater.check().await {
+            Ok(Some(update)) => emit(
+                &app,
+                UpdaterEvent::Available {
+                    version: update.version.clone(),
+                },
+            ),
+            Ok(None) => {}
+            Err(err) => log::warn!("silent update check failed: {err}"),
+        }
+    });
+}
+
+/// Checks for an update, confirms with the user, then downloads, installs
+/// and relaunches the app.
+pub async fn check_and_install<R: Runtime>(app: tauri::AppHandle<R>) -> tauri::Result<()> {
+    emit(&app, UpdaterEvent::Checking);
+
+    let updater = app.updater()?;
+    let update = match updater.check().await {
+        Ok(Some(update)) => update,
+        Ok(None) => {
+            emit(&app, UpdaterEvent::UpToDate);
+            return Ok(());
+        }
+        Err(err) => {
+            emit(
+                &app,
+                UpdaterEvent::Error {
+                    message: err.to_string(),
+                },
+            );
+            return Err(err.into());
+        }
+    };
+
+    emit(
+        &app,
+        UpdaterEvent::Available {
+            version: update.version.clone(),
+        },
+    );
+
+    let confirmed = app
+        .dialog()
+        .message(format!(
+            "Version {} is available. Install and restart now?",
+            update.version
+        ))
+        .kind(MessageDialogKind::Info)
+        .buttons(MessageDialogButtons::OkCancel)
+        .title("Update Available")
+        .blocking_show();
+
+    if !confirmed {
+        return Ok(());
+    }
+
+    let progress_app = app.clone();
+    update
+        .download_and_install(
+            move |chunk_length, content_length| {
+                emit(
+                    &progress_app,
+                    UpdaterEvent::Downloading {
+                        chunk_length,
+                        content_length,
+                    },
+                );
+            },
+            || {},
+        )
+        .await?;
+
+    emit(&app, UpdaterEvent::Downloaded);
+    app.restart();
+}